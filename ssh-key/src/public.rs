@@ -7,13 +7,19 @@ mod dsa;
 #[cfg(feature = "ecdsa")]
 mod ecdsa;
 mod ed25519;
+#[cfg(feature = "encryption")]
+mod encryption;
 mod key_data;
 #[cfg(feature = "alloc")]
 mod opaque;
 #[cfg(feature = "alloc")]
 mod rsa;
 mod sk;
+#[cfg(feature = "alloc")]
+mod spki;
 mod ssh_format;
+#[cfg(feature = "alloc")]
+mod sshfp;
 
 pub use self::{ed25519::Ed25519PublicKey, key_data::KeyData, sk::SkEd25519};
 
@@ -27,6 +33,12 @@ pub use self::{
 #[cfg(feature = "ecdsa")]
 pub use self::{ecdsa::EcdsaPublicKey, sk::SkEcdsaSha2NistP256};
 
+#[cfg(feature = "encryption")]
+pub use self::encryption::{unwrap_key, SshRecipientStanza};
+
+#[cfg(feature = "alloc")]
+pub use self::sshfp::SshfpRecord;
+
 pub(crate) use self::ssh_format::SshFormat;
 
 use crate::{Algorithm, Error, Fingerprint, HashAlg, Result};
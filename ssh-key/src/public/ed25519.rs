@@ -0,0 +1,71 @@
+//! Ed25519 public keys.
+
+#[cfg(feature = "encryption")]
+use {
+    crate::{Error, Result},
+    curve25519_dalek::edwards::CompressedEdwardsY,
+    curve25519_dalek::traits::IsIdentity,
+    zeroize::Zeroizing,
+};
+
+/// Size of an Ed25519 public key in bytes.
+pub const PUBLIC_KEY_SIZE: usize = 32;
+
+/// Ed25519 public key.
+///
+/// Represented as the raw 32-byte compressed Edwards `y`-coordinate, as
+/// encoded on the wire by the `ssh-ed25519` algorithm.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Ed25519PublicKey(pub [u8; PUBLIC_KEY_SIZE]);
+
+impl Ed25519PublicKey {
+    /// Borrow the public key data as bytes.
+    pub fn as_bytes(&self) -> &[u8; PUBLIC_KEY_SIZE] {
+        &self.0
+    }
+
+    /// Convert this Ed25519 public key to the X25519 public key that shares
+    /// the same underlying curve point, using the standard
+    /// `crypto_sign_ed25519_pk_to_curve25519` birational map
+    /// `u = (1+y)/(1-y) mod 2^255-19`.
+    ///
+    /// This lets an existing SSH Ed25519 identity be reused as an X25519
+    /// key, e.g. to open a NaCl/libsodium sealed box.
+    ///
+    /// Returns [`Error::Crypto`] if the encoded point doesn't decompress to
+    /// a valid curve point, or is the identity (`y = 1`), for which the map
+    /// divides by zero.
+    #[cfg(feature = "encryption")]
+    pub fn to_x25519(&self) -> Result<[u8; 32]> {
+        let edwards_point = CompressedEdwardsY(self.0)
+            .decompress()
+            .ok_or(Error::Crypto)?;
+
+        if edwards_point.is_identity() {
+            return Err(Error::Crypto);
+        }
+
+        Ok(edwards_point.to_montgomery().to_bytes())
+    }
+}
+
+/// Convert an Ed25519 private key seed to the X25519 secret scalar that
+/// shares the same underlying curve point as [`Ed25519PublicKey::to_x25519`],
+/// using the standard `crypto_sign_ed25519_sk_to_curve25519` mapping (the
+/// first 32 bytes of SHA-512 of the seed, clamped per RFC 7748).
+///
+/// Crate-internal for now: there's no `PrivateKey` type in this tree to hang
+/// a public method off of, and private-key material shouldn't be returned
+/// unwrapped from a public API anyway.
+#[cfg(feature = "encryption")]
+pub(crate) fn ed25519_sk_to_x25519(seed: &[u8; PUBLIC_KEY_SIZE]) -> Zeroizing<[u8; 32]> {
+    use sha2::{Digest, Sha512};
+
+    let hash = Sha512::digest(seed);
+    let mut scalar = Zeroizing::new([0u8; 32]);
+    scalar.copy_from_slice(&hash[..32]);
+    scalar[0] &= 248;
+    scalar[31] &= 127;
+    scalar[31] |= 64;
+    scalar
+}
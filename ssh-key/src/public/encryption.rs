@@ -0,0 +1,170 @@
+//! Public-key encryption to `ssh-ed25519` recipients.
+//!
+//! This implements a recipient-stanza scheme modeled on [age]'s design (an
+//! X25519 key agreement wrapping a file key under ChaCha20-Poly1305), so an
+//! existing SSH identity can double as a file-encryption recipient without
+//! generating a dedicated `age` key. It is *not* wire-compatible with `age`
+//! itself: the HKDF `info` string and stanza layout here are specific to
+//! this crate, not `age`'s `ssh-ed25519` recipient type.
+//!
+//! [age]: https://github.com/FiloSottile/age
+
+use super::Ed25519PublicKey;
+use crate::{Error, Result};
+use alloc::vec::Vec;
+use chacha20poly1305::{aead::AeadInPlace, ChaCha20Poly1305, KeyInit};
+use curve25519_dalek::montgomery::MontgomeryPoint;
+use hkdf::Hkdf;
+use sha2::{Digest, Sha256};
+
+/// Label used as the HKDF `info` parameter for this crate's `ssh-ed25519`
+/// recipient stanza.
+const HKDF_INFO: &[u8] = b"ssh-ed25519";
+
+/// Size in bytes of the recipient tag prefixed to each stanza.
+const TAG_SIZE: usize = 4;
+
+/// A recipient stanza produced by [`super::PublicKey::wrap_key`].
+///
+/// Carries everything a holder of the matching Ed25519 private key needs in
+/// order to recover the wrapped file key.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SshRecipientStanza {
+    /// First 4 bytes of SHA-256 of the recipient's SSH wire-encoded public key.
+    tag: [u8; TAG_SIZE],
+
+    /// Ephemeral X25519 public key generated for this stanza.
+    ephemeral_public_key: [u8; 32],
+
+    /// `file_key` encrypted under the derived wrapping key.
+    ciphertext: Vec<u8>,
+}
+
+impl SshRecipientStanza {
+    /// Recipient tag: first 4 bytes of SHA-256 of the recipient's SSH wire encoding.
+    pub fn tag(&self) -> [u8; TAG_SIZE] {
+        self.tag
+    }
+
+    /// Ephemeral X25519 public key used to derive the wrapping key.
+    pub fn ephemeral_public_key(&self) -> [u8; 32] {
+        self.ephemeral_public_key
+    }
+
+    /// Ciphertext of the wrapped file key.
+    pub fn ciphertext(&self) -> &[u8] {
+        &self.ciphertext
+    }
+}
+
+/// Derive the recipient tag for an SSH public key: the first 4 bytes of
+/// SHA-256 of its SSH wire encoding.
+fn recipient_tag(ssh_wire_public_key: &[u8]) -> [u8; TAG_SIZE] {
+    let digest = Sha256::digest(ssh_wire_public_key);
+    let mut tag = [0u8; TAG_SIZE];
+    tag.copy_from_slice(&digest[..TAG_SIZE]);
+    tag
+}
+
+/// Derive the ChaCha20-Poly1305 wrapping key shared between an ephemeral
+/// X25519 keypair and a recipient's X25519 public key.
+fn derive_wrap_key(
+    ephemeral_public_key: &[u8; 32],
+    recipient_public_key: &[u8; 32],
+    shared_secret: &[u8; 32],
+) -> [u8; 32] {
+    let mut salt = Vec::with_capacity(64);
+    salt.extend_from_slice(ephemeral_public_key);
+    salt.extend_from_slice(recipient_public_key);
+
+    let mut wrap_key = [0u8; 32];
+    Hkdf::<Sha256>::new(Some(&salt), shared_secret)
+        .expand(HKDF_INFO, &mut wrap_key)
+        .expect("32-byte output is a valid HKDF-SHA256 length");
+
+    wrap_key
+}
+
+impl super::PublicKey {
+    /// Wrap a symmetric `file_key` to this public key, producing an
+    /// [`SshRecipientStanza`].
+    ///
+    /// Only `ssh-ed25519` keys are currently supported as recipients.
+    #[cfg(feature = "encryption")]
+    pub fn wrap_key(&self, file_key: &[u8]) -> Result<SshRecipientStanza> {
+        if self.algorithm().as_str() != "ssh-ed25519" {
+            return Err(Error::AlgorithmUnknown);
+        }
+
+        let ssh_wire_public_key = self.to_bytes()?;
+
+        // The Ed25519 point is the last 32 bytes of the `ssh-ed25519` wire encoding.
+        let mut ed25519_public_key = [0u8; 32];
+        ed25519_public_key.copy_from_slice(&ssh_wire_public_key[ssh_wire_public_key.len() - 32..]);
+
+        let recipient_public_key = Ed25519PublicKey(ed25519_public_key).to_x25519()?;
+
+        // `mul_base_clamped`/`mul_clamped` apply RFC 7748 clamping to the
+        // scalar bytes internally.
+        let ephemeral_scalar_bytes = rand_scalar_bytes()?;
+        let ephemeral_public_key = MontgomeryPoint::mul_base_clamped(ephemeral_scalar_bytes).to_bytes();
+        let shared_secret =
+            MontgomeryPoint(recipient_public_key).mul_clamped(ephemeral_scalar_bytes).to_bytes();
+
+        let wrap_key = derive_wrap_key(&ephemeral_public_key, &recipient_public_key, &shared_secret);
+
+        let mut ciphertext = file_key.to_vec();
+        let tag = ChaCha20Poly1305::new((&wrap_key).into())
+            .encrypt_in_place_detached(&[0u8; 12].into(), b"", &mut ciphertext)
+            .map_err(|_| Error::Crypto)?;
+        ciphertext.extend_from_slice(&tag);
+
+        Ok(SshRecipientStanza {
+            tag: recipient_tag(&ssh_wire_public_key),
+            ephemeral_public_key,
+            ciphertext,
+        })
+    }
+}
+
+/// Unwrap a file key from an [`SshRecipientStanza`] using the holder's
+/// Ed25519 private key seed.
+///
+/// This mirrors what will become `PrivateKey::unwrap_key` once this crate's
+/// private-key module is vendored alongside `public.rs`; until then it's
+/// exposed as a free function operating directly on the 32-byte Ed25519
+/// private key seed.
+#[cfg(feature = "encryption")]
+pub fn unwrap_key(
+    ed25519_private_key_seed: &[u8; 32],
+    stanza: &SshRecipientStanza,
+) -> Result<Vec<u8>> {
+    // `ed25519_sk_to_x25519` already applies RFC 7748 clamping; `mul_clamped`
+    // re-clamps the bytes it's given, which is a no-op on already-clamped input.
+    let scalar_bytes = *super::ed25519::ed25519_sk_to_x25519(ed25519_private_key_seed);
+    let shared_secret =
+        MontgomeryPoint(stanza.ephemeral_public_key).mul_clamped(scalar_bytes).to_bytes();
+
+    let recipient_public_key = MontgomeryPoint::mul_base_clamped(scalar_bytes).to_bytes();
+    let wrap_key = derive_wrap_key(&stanza.ephemeral_public_key, &recipient_public_key, &shared_secret);
+
+    if stanza.ciphertext.len() < 16 {
+        return Err(Error::Crypto);
+    }
+    let (body, tag) = stanza.ciphertext.split_at(stanza.ciphertext.len() - 16);
+    let mut file_key = body.to_vec();
+    ChaCha20Poly1305::new((&wrap_key).into())
+        .decrypt_in_place_detached(&[0u8; 12].into(), b"", &mut file_key, tag.into())
+        .map_err(|_| Error::Crypto)?;
+
+    Ok(file_key)
+}
+
+/// Generate 32 bytes of randomness for an ephemeral X25519 scalar.
+fn rand_scalar_bytes() -> Result<[u8; 32]> {
+    use rand_core::{OsRng, RngCore};
+
+    let mut bytes = [0u8; 32];
+    OsRng.try_fill_bytes(&mut bytes).map_err(|_| Error::Crypto)?;
+    Ok(bytes)
+}
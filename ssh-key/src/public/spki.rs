@@ -0,0 +1,202 @@
+//! Conversion between the OpenSSH wire format and X.509
+//! `SubjectPublicKeyInfo` (SPKI) DER/PEM, so SSH public keys can
+//! interoperate with TLS/PKIX tooling.
+//!
+//! Each [`super::KeyData`] variant maps onto a `SubjectPublicKeyInfo` whose
+//! `algorithm` field carries a standard OID and whose `subjectPublicKey`
+//! carries the corresponding standard encoding of the key (raw point for
+//! Ed25519, a DER `RSAPublicKey` for RSA, a SEC1 uncompressed point for
+//! ECDSA). The [`super::PublicKey`] comment has no SPKI equivalent and is
+//! dropped on export.
+
+use crate::{Error, Result};
+use alloc::{string::String, vec::Vec};
+use der::{
+    asn1::{AnyRef, BitStringRef, ObjectIdentifier},
+    Decode, Encode,
+};
+use pem_rfc7468::LineEnding;
+use pkcs1::{RsaPublicKey as Pkcs1RsaPublicKey, UintRef};
+use spki::{AlgorithmIdentifier, SubjectPublicKeyInfo, SubjectPublicKeyInfoRef};
+
+/// PEM label used for `SubjectPublicKeyInfo` documents.
+const PEM_LABEL: &str = "PUBLIC KEY";
+
+const OID_ED25519: ObjectIdentifier = ObjectIdentifier::new_unwrap("1.3.101.112");
+const OID_RSA_ENCRYPTION: ObjectIdentifier = ObjectIdentifier::new_unwrap("1.2.840.113549.1.1.1");
+const OID_EC_PUBLIC_KEY: ObjectIdentifier = ObjectIdentifier::new_unwrap("1.2.840.10045.2.1");
+const OID_NIST_P256: ObjectIdentifier = ObjectIdentifier::new_unwrap("1.2.840.10045.3.1.7");
+const OID_NIST_P384: ObjectIdentifier = ObjectIdentifier::new_unwrap("1.3.132.0.34");
+const OID_NIST_P521: ObjectIdentifier = ObjectIdentifier::new_unwrap("1.3.132.0.35");
+
+/// Read an SSH wire `string` (4-byte big-endian length prefix), returning
+/// `(contents, rest)`.
+///
+/// This is the OpenSSH wire format, not DER, so it's hand-rolled like the
+/// rest of this crate's SSH codec rather than pulled from a DER library;
+/// `split_at_checked` rejects a truncated/malformed length instead of
+/// panicking.
+fn read_ssh_string(buf: &[u8]) -> Result<(&[u8], &[u8])> {
+    let (len_bytes, rest) = buf.split_at_checked(4).ok_or(Error::Crypto)?;
+    let len = u32::from_be_bytes(len_bytes.try_into().expect("4 bytes")) as usize;
+    rest.split_at_checked(len).ok_or(Error::Crypto)
+}
+
+fn encode_ssh_string(bytes: &[u8], out: &mut Vec<u8>) {
+    out.extend_from_slice(&(bytes.len() as u32).to_be_bytes());
+    out.extend_from_slice(bytes);
+}
+
+/// Encode a non-negative integer as an SSH wire `mpint` ([RFC 4251 section 5]):
+/// like [`encode_ssh_string`], but an extra `0x00` sign byte is prepended
+/// when the high bit of `bytes` would otherwise be mistaken for a negative
+/// two's-complement value. [`pkcs1::UintRef::as_bytes`] strips DER's own
+/// leading `0x00` pad, so that byte has to be reinstated here for values
+/// like an RSA modulus, which always has its top bit set.
+///
+/// [RFC 4251 section 5]: https://www.rfc-editor.org/rfc/rfc4251#section-5
+fn encode_ssh_mpint(bytes: &[u8], out: &mut Vec<u8>) {
+    if bytes.first().is_some_and(|&b| b & 0x80 != 0) {
+        out.extend_from_slice(&((bytes.len() + 1) as u32).to_be_bytes());
+        out.push(0);
+    } else {
+        out.extend_from_slice(&(bytes.len() as u32).to_be_bytes());
+    }
+    out.extend_from_slice(bytes);
+}
+
+impl super::PublicKey {
+    /// Encode this public key as a DER-encoded X.509
+    /// `SubjectPublicKeyInfo`.
+    pub fn to_spki_der(&self) -> Result<Vec<u8>> {
+        let wire = self.to_bytes()?;
+        let algorithm_id = self.algorithm();
+
+        let (algorithm, subject_public_key): (AlgorithmIdentifier<AnyRef<'_>>, Vec<u8>) =
+            match algorithm_id.as_str() {
+                "ssh-ed25519" => {
+                    let (name, rest) = read_ssh_string(&wire)?;
+                    debug_assert_eq!(name, b"ssh-ed25519");
+                    let (point, _) = read_ssh_string(rest)?;
+                    (
+                        AlgorithmIdentifier {
+                            oid: OID_ED25519,
+                            parameters: None,
+                        },
+                        point.to_vec(),
+                    )
+                }
+                "ssh-rsa" => {
+                    let (_name, rest) = read_ssh_string(&wire)?;
+                    let (e, rest) = read_ssh_string(rest)?;
+                    let (n, _) = read_ssh_string(rest)?;
+                    let rsa_public_key = Pkcs1RsaPublicKey {
+                        modulus: UintRef::new(n).map_err(|_| Error::Crypto)?,
+                        public_exponent: UintRef::new(e).map_err(|_| Error::Crypto)?,
+                    };
+                    let der = rsa_public_key.to_der().map_err(|_| Error::Crypto)?;
+                    (
+                        AlgorithmIdentifier {
+                            oid: OID_RSA_ENCRYPTION,
+                            parameters: Some(AnyRef::from(der::asn1::Null)),
+                        },
+                        der,
+                    )
+                }
+                name if name.starts_with("ecdsa-sha2-") => {
+                    let (_name, rest) = read_ssh_string(&wire)?;
+                    let (curve, rest) = read_ssh_string(rest)?;
+                    let (point, _) = read_ssh_string(rest)?;
+                    // `AnyRef::from(&'static ObjectIdentifier)` is borrowed directly
+                    // from the named consts (not through a local copy) so each arm's
+                    // reference is promotable to 'static, outliving `to_der()` below.
+                    let parameters = match curve {
+                        b"nistp256" => AnyRef::from(&OID_NIST_P256),
+                        b"nistp384" => AnyRef::from(&OID_NIST_P384),
+                        b"nistp521" => AnyRef::from(&OID_NIST_P521),
+                        _ => return Err(Error::AlgorithmUnknown),
+                    };
+                    (
+                        AlgorithmIdentifier {
+                            oid: OID_EC_PUBLIC_KEY,
+                            parameters: Some(parameters),
+                        },
+                        point.to_vec(),
+                    )
+                }
+                _ => return Err(Error::AlgorithmUnknown),
+            };
+
+        let spki = SubjectPublicKeyInfoRef {
+            algorithm,
+            subject_public_key: BitStringRef::from_bytes(&subject_public_key)
+                .map_err(|_| Error::Crypto)?,
+        };
+
+        spki.to_der().map_err(|_| Error::Crypto)
+    }
+
+    /// Decode a public key from a DER-encoded X.509
+    /// `SubjectPublicKeyInfo`.
+    pub fn from_spki_der(der_bytes: &[u8]) -> Result<Self> {
+        let spki = SubjectPublicKeyInfoRef::from_der(der_bytes).map_err(|_| Error::Crypto)?;
+        let subject_public_key = spki.subject_public_key.raw_bytes();
+
+        let mut wire = Vec::new();
+        match spki.algorithm.oid {
+            OID_ED25519 => {
+                encode_ssh_string(b"ssh-ed25519", &mut wire);
+                encode_ssh_string(subject_public_key, &mut wire);
+            }
+            OID_RSA_ENCRYPTION => {
+                let rsa_public_key =
+                    Pkcs1RsaPublicKey::from_der(subject_public_key).map_err(|_| Error::Crypto)?;
+                encode_ssh_string(b"ssh-rsa", &mut wire);
+                encode_ssh_mpint(rsa_public_key.public_exponent.as_bytes(), &mut wire);
+                encode_ssh_mpint(rsa_public_key.modulus.as_bytes(), &mut wire);
+            }
+            OID_EC_PUBLIC_KEY => {
+                let curve_oid = spki
+                    .algorithm
+                    .parameters
+                    .and_then(|any| any.decode_as::<ObjectIdentifier>().ok())
+                    .ok_or(Error::Crypto)?;
+                let curve_name: &[u8] = match curve_oid {
+                    OID_NIST_P256 => b"nistp256",
+                    OID_NIST_P384 => b"nistp384",
+                    OID_NIST_P521 => b"nistp521",
+                    _ => return Err(Error::AlgorithmUnknown),
+                };
+                let mut name = Vec::with_capacity(19);
+                name.extend_from_slice(b"ecdsa-sha2-");
+                name.extend_from_slice(curve_name);
+                encode_ssh_string(&name, &mut wire);
+                encode_ssh_string(curve_name, &mut wire);
+                encode_ssh_string(subject_public_key, &mut wire);
+            }
+            _ => return Err(Error::AlgorithmUnknown),
+        }
+
+        Self::from_bytes(&wire)
+    }
+
+    /// Encode this public key as a PEM-encoded X.509
+    /// `SubjectPublicKeyInfo` (a.k.a. a PKIX "PUBLIC KEY" document).
+    pub fn to_public_key_pem(&self) -> Result<String> {
+        let der = self.to_spki_der()?;
+        pem_rfc7468::encode_string(PEM_LABEL, LineEnding::LF, &der).map_err(|_| Error::Crypto)
+    }
+
+    /// Decode a public key from a PEM-encoded X.509
+    /// `SubjectPublicKeyInfo`.
+    pub fn from_public_key_pem(pem: &str) -> Result<Self> {
+        let (label, der) =
+            pem_rfc7468::decode_vec(pem.as_bytes()).map_err(|_| Error::Crypto)?;
+
+        if label != PEM_LABEL {
+            return Err(Error::Crypto);
+        }
+
+        Self::from_spki_der(&der)
+    }
+}
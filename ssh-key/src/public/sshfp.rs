@@ -0,0 +1,102 @@
+//! SSHFP DNS resource records ([RFC 4255]), for publishing and pinning host
+//! keys via DNS(SEC).
+//!
+//! [RFC 4255]: https://www.rfc-editor.org/rfc/rfc4255
+
+use crate::{Error, Result};
+use alloc::{format, string::String};
+use core::fmt;
+use sha2::{Digest, Sha256};
+
+/// The SSHFP "fingerprint type" this crate produces ([RFC 4255 section 3.2]):
+/// SHA-256 only. RFC 4255 also defines type 1 for SHA-1, but this crate's
+/// `HashAlg` has no `Sha1` variant, so that RDATA value can't be produced;
+/// there's accordingly no `hash_alg` parameter to pick it.
+///
+/// [RFC 4255 section 3.2]: https://www.rfc-editor.org/rfc/rfc4255#section-3.2
+const FINGERPRINT_TYPE_SHA256: u8 = 2;
+
+/// The SSHFP "algorithm number" identifying a public key algorithm ([RFC 4255 section 3.1]).
+///
+/// [RFC 4255 section 3.1]: https://www.rfc-editor.org/rfc/rfc4255#section-3.1
+fn algorithm_number(algorithm_id: &str) -> Result<u8> {
+    match algorithm_id {
+        "ssh-rsa" => Ok(1),
+        "ssh-dss" => Ok(2),
+        id if id.starts_with("ecdsa-sha2-") => Ok(3),
+        "ssh-ed25519" => Ok(4),
+        _ => Err(Error::AlgorithmUnknown),
+    }
+}
+
+/// An SSHFP DNS resource record, as used to publish or pin an SSH host key
+/// via DNS.
+///
+/// Formats as the three RDATA fields of a zone-file SSHFP record:
+/// `algorithm fp_type hexdigest`.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SshfpRecord {
+    /// Public key algorithm number.
+    algorithm: u8,
+
+    /// Fingerprint (digest) type number.
+    fingerprint_type: u8,
+
+    /// Lowercase-hex fingerprint of the raw SSH public key blob.
+    hexdigest: String,
+}
+
+impl SshfpRecord {
+    /// Public key algorithm number (1=RSA, 2=DSA, 3=ECDSA, 4=Ed25519).
+    pub fn algorithm(&self) -> u8 {
+        self.algorithm
+    }
+
+    /// Fingerprint type number. Always 2 (SHA-256); see
+    /// [`super::PublicKey::sshfp_record`] for why RFC 4255's SHA-1 type 1
+    /// isn't available.
+    pub fn fingerprint_type(&self) -> u8 {
+        self.fingerprint_type
+    }
+
+    /// Lowercase-hex fingerprint of the raw SSH public key blob.
+    pub fn hexdigest(&self) -> &str {
+        &self.hexdigest
+    }
+}
+
+impl fmt::Display for SshfpRecord {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {} {}", self.algorithm, self.fingerprint_type, self.hexdigest)
+    }
+}
+
+impl super::PublicKey {
+    /// Generate an [`SshfpRecord`] for publishing this key as a host key via
+    /// DNS ([RFC 4255]).
+    ///
+    /// The digest is computed over the same raw SSH key blob that
+    /// [`super::PublicKey::fingerprint`] hashes, but rendered as lowercase
+    /// hex instead of `SHA256:`-prefixed Base64, and always using SHA-256
+    /// (RFC 4255 fingerprint type 2): this crate's `HashAlg` has no `Sha1`
+    /// variant, so RFC 4255's SHA-1 type 1 can't be produced, and there's
+    /// accordingly no `hash_alg` argument here to request it.
+    ///
+    /// [RFC 4255]: https://www.rfc-editor.org/rfc/rfc4255
+    pub fn sshfp_record(&self) -> Result<SshfpRecord> {
+        let algorithm = algorithm_number(self.algorithm().as_str())?;
+        let wire = self.to_bytes()?;
+        let digest = Sha256::digest(&wire);
+
+        let mut hexdigest = String::with_capacity(digest.len() * 2);
+        for byte in &digest {
+            hexdigest.push_str(&format!("{byte:02x}"));
+        }
+
+        Ok(SshfpRecord {
+            algorithm,
+            fingerprint_type: FINGERPRINT_TYPE_SHA256,
+            hexdigest,
+        })
+    }
+}